@@ -1,3 +1,8 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
 fn main() {
     // ----- [1] vector of numbers to string (separated by spaces) -----
     let x1 = vec![1, 2, 3, 4, 5];
@@ -209,17 +214,8 @@ fn main() {
     println!("");
 
     // ----- [42] Iterator folding into struc ----
-    #[derive(Debug)]
-    struct Stats {
-        sum: i32,
-        count: i32,
-    }
-    let stats = (1..6).fold(Stats { sum: 0, count: 0 }, |mut s, x| {
-        s.sum += x;
-        s.count += 1;
-        s
-    });
-    println!("{:?}", stats);
+    let stats = Stats::from_iter(1..6);
+    println!("{:?}", stats); // Some(Stats { count: 5, sum: 15, min: 1, max: 5 })
 
     // ----- [43] Functional factorial ----
     let prod = (1..=10).product::<u64>();
@@ -270,9 +266,6 @@ fn main() {
     println!("sums = {:?}", sums); //
 
     // ----- [48] Recursive functional loop (Fibonacci) -----
-    fn fib(n: u32) -> u32 {
-        if n < 2 { n } else { fib(n - 2) + fib(n - 1) }
-    }
     for i in 0..10 {
         print!("{} ", fib(i))
     }
@@ -291,4 +284,1741 @@ fn main() {
         idx = (idx + 1) % 5;
         print!("{idx} ");
     }
+    println!();
+
+    // ----- Tests -----
+    test_sum_squared();
+    test_tally();
+    test_min_max();
+    test_chunked_sum();
+    test_stats();
+    test_zip3();
+    test_cartesian_product();
+    test_batches();
+    test_dedup_consecutive();
+    test_pad_to();
+    test_sliding_max();
+    test_prefix_sums();
+    test_unzip3();
+    test_join_display();
+    test_take_every();
+    test_count_matching();
+    test_first_and_last();
+    test_circular();
+    test_flatten_and_scale();
+    test_partition_three();
+    test_windows_map();
+    test_positions();
+    test_try_collect_ints();
+    test_reduce_or_default();
+    test_join_words();
+    test_merge_sorted();
+    test_filter_map_indexed();
+    test_chain_all();
+    test_nth_or_last();
+    test_split_at_predicate();
+    test_running_min_max();
+    test_histogram();
+    test_enumerate_from();
+    test_checked_sum();
+    test_step_range_inclusive();
+    test_transpose();
+    test_moving_average();
+    test_accumulate_while();
+    test_ring_buffer();
+    test_iter_pairs();
+    test_find_index();
+    test_take_until();
+    test_group_runs_len();
+    test_scan_sum();
+    test_compose();
+    test_fib_memo();
+    test_factorial_checked();
+    test_chunks_exact_vec();
+    test_try_reduce();
+    test_dedup_approx();
+    test_enumerate_matrix();
+    test_count_runs();
+    test_pairwise_diff();
+    test_top_n();
+    test_with_previous();
+    test_weighted_sum();
+    test_ordered_set();
+    test_flatten_options();
+    test_partition_results();
+    test_chunk_by_sum();
+    test_product_checked();
+    test_zip_with();
+    test_all_unique();
+    test_most_common();
+    test_split_chunks();
+    test_tokenize_ints();
+    test_sum_by();
+    test_argmax_argmin();
+    test_repeat_each();
+    test_sliding_window_stats();
+    test_chunk_averages();
+    test_find_map_indexed();
+    test_scan_state();
+    test_interleave_all();
+    test_memoize();
+    test_split_on();
+    test_rotate_iter();
+}
+
+// ==========================================================
+// Iterator extension trait
+// ==========================================================
+// Blanket-implemented for every iterator, so the helpers read like
+// built-in adapters: (1..=3).sum_squared()
+trait IterExt: Iterator + Sized {
+    // Square each element (widened to u64 so it cannot overflow u32) and sum
+    fn sum_squared(self) -> u64
+    where
+        Self: Iterator<Item = u32>,
+    {
+        self.map(|x| x as u64 * x as u64).sum()
+    }
+
+    // Count how many times each distinct item occurs
+    fn tally<T>(self) -> HashMap<T, usize>
+    where
+        Self: Iterator<Item = T>,
+        T: Eq + Hash,
+    {
+        self.fold(HashMap::new(), |mut counts, x| {
+            *counts.entry(x).or_insert(0) += 1;
+            counts
+        })
+    }
+
+    // Smallest and largest item in a single pass (None when empty)
+    fn min_max<T>(self) -> Option<(T, T)>
+    where
+        Self: Iterator<Item = T>,
+        T: Ord + Copy,
+    {
+        self.fold(None, |acc, x| match acc {
+            None => Some((x, x)),
+            Some((lo, hi)) => Some((lo.min(x), hi.max(x))),
+        })
+    }
+
+    // Group items into Vecs of n; the final batch may be shorter.
+    // Panics if n == 0, like slice::chunks.
+    fn batches(self, n: usize) -> Batches<Self> {
+        assert!(n > 0, "batch size must be non-zero");
+        Batches { iter: self, n }
+    }
+
+    // Skip items equal to the one yielded just before (lazy Vec::dedup, see [14])
+    fn dedup_consecutive(self) -> DedupConsecutive<Self>
+    where
+        Self::Item: PartialEq + Clone,
+    {
+        DedupConsecutive { iter: self, last: None }
+    }
+
+    // Yield every item, then repeat filler until len items have been produced.
+    // Sources already len long (or longer) pass through unchanged.
+    fn pad_to(self, len: usize, filler: Self::Item) -> PadTo<Self>
+    where
+        Self::Item: Clone,
+    {
+        PadTo { iter: self.fuse(), len, produced: 0, filler }
+    }
+
+    // Maximum of every window of consecutive items.
+    // Panics if window == 0, like slice::windows.
+    fn sliding_max<T>(self, window: usize) -> SlidingMax<Self, T>
+    where
+        Self: Iterator<Item = T>,
+        T: Ord + Copy,
+    {
+        assert!(window > 0, "window size must be non-zero");
+        SlidingMax { iter: self, window, index: 0, deque: VecDeque::new() }
+    }
+
+    // Lazy running total: each item is the sum of everything seen so far
+    fn cumulative_sum(self) -> impl Iterator<Item = i64>
+    where
+        Self: Iterator<Item = i64>,
+    {
+        self.scan(0, |acc, x| {
+            *acc += x;
+            Some(*acc)
+        })
+    }
+
+    // Every nth item starting with the first, i.e. step_by written by hand.
+    // Panics if n == 0.
+    fn take_every(self, n: usize) -> impl Iterator<Item = Self::Item> {
+        assert!(n > 0, "step must be non-zero");
+        self.enumerate().filter(move |(i, _)| i % n == 0).map(|(_, x)| x)
+    }
+
+    // Indices of the items matching pred: enumerate().filter().map() condensed
+    fn positions<P>(self, pred: P) -> impl Iterator<Item = usize>
+    where
+        P: Fn(&Self::Item) -> bool,
+    {
+        self.enumerate().filter(move |(_, x)| pred(x)).map(|(i, _)| i)
+    }
+
+    // filter_map (see [12]) whose closure also sees the item's index
+    fn filter_map_indexed<U, F>(self, f: F) -> impl Iterator<Item = U>
+    where
+        F: FnMut((usize, Self::Item)) -> Option<U>,
+    {
+        self.enumerate().filter_map(f)
+    }
+
+    // The nth item, or the last one if the iterator is shorter (None if empty)
+    fn nth_or_last(mut self, n: usize) -> Option<Self::Item> {
+        let mut last = None;
+        for _ in 0..=n {
+            match self.next() {
+                Some(x) => last = Some(x),
+                None => break,
+            }
+        }
+        last
+    }
+
+    // Smallest item seen so far, after every item
+    fn running_min<T>(self) -> impl Iterator<Item = T>
+    where
+        Self: Iterator<Item = T>,
+        T: Ord + Copy,
+    {
+        self.scan(None, |acc: &mut Option<T>, x| {
+            let m = acc.map_or(x, |m| m.min(x));
+            *acc = Some(m);
+            Some(m)
+        })
+    }
+
+    // Largest item seen so far, after every item
+    fn running_max<T>(self) -> impl Iterator<Item = T>
+    where
+        Self: Iterator<Item = T>,
+        T: Ord + Copy,
+    {
+        self.scan(None, |acc: &mut Option<T>, x| {
+            let m = acc.map_or(x, |m| m.max(x));
+            *acc = Some(m);
+            Some(m)
+        })
+    }
+
+    // enumerate() with a custom first index, e.g. 1 for line numbers
+    fn enumerate_from(self, start: usize) -> impl Iterator<Item = (usize, Self::Item)> {
+        (start..).zip(self)
+    }
+
+    // Mean of every window of consecutive values.
+    // Panics if window == 0.
+    fn moving_average(self, window: usize) -> MovingAverage<Self>
+    where
+        Self: Iterator<Item = f64>,
+    {
+        assert!(window > 0, "window size must be non-zero");
+        MovingAverage { iter: self, window, buf: VecDeque::with_capacity(window), sum: 0.0 }
+    }
+
+    // Fold like fold(), but yield every intermediate accumulator and stop
+    // as soon as pred rejects one (that value is not yielded)
+    fn accumulate_while<B, F, P>(self, init: B, mut f: F, pred: P) -> impl Iterator<Item = B>
+    where
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+        P: Fn(&B) -> bool,
+    {
+        self.scan(init, move |acc, x| {
+            *acc = f(acc.clone(), x);
+            if pred(acc) { Some(acc.clone()) } else { None }
+        })
+    }
+
+    // Overlapping consecutive pairs: [1, 2, 3] -> (1, 2), (2, 3).
+    // Like windows(2) but for any iterator, not just slices.
+    fn iter_pairs(self) -> IterPairs<Self>
+    where
+        Self::Item: Clone,
+    {
+        IterPairs { iter: self, prev: None }
+    }
+
+    // Index of the first item matching pred; find() ([16]) returns the item itself
+    fn find_index<P: Fn(&Self::Item) -> bool>(mut self, pred: P) -> Option<usize> {
+        self.position(|x| pred(&x))
+    }
+
+    // Inclusive take_while: yield items up to and including the first one
+    // matching pred, then stop
+    fn take_until<P: Fn(&Self::Item) -> bool>(self, pred: P) -> impl Iterator<Item = Self::Item> {
+        let mut done = false;
+        self.take_while(move |x| {
+            if done {
+                return false;
+            }
+            done = pred(x);
+            true
+        })
+    }
+
+    // Length of every run of equal consecutive items (compact form of [14])
+    fn group_runs_len(self) -> GroupRunsLen<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        GroupRunsLen { iter: self.peekable() }
+    }
+
+    // Each item paired with the running total including it: where map
+    // sees one item and fold only the end result, scan sees both
+    fn scan_sum(self) -> impl Iterator<Item = (i64, i64)>
+    where
+        Self: Iterator<Item = i64>,
+    {
+        self.scan(0, |acc, x| {
+            *acc += x;
+            Some((x, *acc))
+        })
+    }
+
+    // dedup_consecutive for floats: drop a value within epsilon of the
+    // previously *yielded* one (exact == is rarely meaningful for f64)
+    fn dedup_approx(self, epsilon: f64) -> impl Iterator<Item = f64>
+    where
+        Self: Iterator<Item = f64>,
+    {
+        let mut last: Option<f64> = None;
+        self.filter(move |&x| {
+            if last.is_some_and(|l| (x - l).abs() <= epsilon) {
+                return false;
+            }
+            last = Some(x);
+            true
+        })
+    }
+
+    // Number of maximal runs of equal consecutive items: [1, 1, 2, 2, 1] -> 3
+    fn count_runs(self) -> usize
+    where
+        Self::Item: PartialEq,
+    {
+        self.group_runs_len().count()
+    }
+
+    // Differences between consecutive values: [1, 3, 6, 10] -> [2, 3, 4]
+    fn pairwise_diff(self) -> impl Iterator<Item = i64>
+    where
+        Self: Iterator<Item = i64>,
+    {
+        self.iter_pairs().map(|(a, b)| b - a)
+    }
+
+    // (None, first), then (Some(prev), cur) for every later item
+    fn with_previous(self) -> impl Iterator<Item = (Option<Self::Item>, Self::Item)>
+    where
+        Self::Item: Clone,
+    {
+        self.scan(None, |prev, cur: Self::Item| {
+            Some((prev.replace(cur.clone()), cur))
+        })
+    }
+
+    // Greedy batching by total weight: keep adding items to the current
+    // chunk until the next one would push its sum over limit. An item that
+    // exceeds limit on its own still gets a chunk of its own.
+    fn chunk_by_sum(self, limit: i64) -> ChunkBySum<Self>
+    where
+        Self: Iterator<Item = i64>,
+    {
+        ChunkBySum { iter: self.peekable(), limit }
+    }
+
+    // product() that reports overflow, the checked_sum counterpart
+    fn product_checked(mut self) -> Option<i64>
+    where
+        Self: Iterator<Item = i64>,
+    {
+        self.try_fold(1i64, |acc, x| acc.checked_mul(x))
+    }
+
+    // Sum a projection of each item; f only borrows the item
+    fn sum_by<F: Fn(&Self::Item) -> i64>(self, f: F) -> i64 {
+        self.fold(0, |acc, x| acc + f(&x))
+    }
+
+    // Yield every item n times in a row; n == 0 yields nothing at all
+    fn repeat_each(self, n: usize) -> impl Iterator<Item = Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        self.flat_map(move |x| std::iter::repeat_n(x, n))
+    }
+
+    // find_map whose closure also sees the item's index
+    fn find_map_indexed<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnMut((usize, Self::Item)) -> Option<U>,
+    {
+        self.enumerate().find_map(f)
+    }
+
+    // scan() spelled out with a named adapter: f mutates the state and
+    // returns the next item, or None to end the iteration
+    fn scan_state<S, B, F>(self, init: S, f: F) -> Scanner<Self, S, F>
+    where
+        F: FnMut(&mut S, Self::Item) -> Option<B>,
+    {
+        Scanner { iter: self, state: init, f }
+    }
+
+    // Split into segments at items matching pred, dropping the delimiters.
+    // Same rules as slice::split: adjacent delimiters produce an empty
+    // segment, and an empty input produces one empty segment.
+    fn split_on<P: Fn(&Self::Item) -> bool>(self, pred: P) -> Vec<Vec<Self::Item>>
+    where
+        Self::Item: Clone,
+    {
+        let mut segments = vec![Vec::new()];
+        for x in self {
+            if pred(&x) {
+                segments.push(Vec::new());
+            } else {
+                segments.last_mut().unwrap().push(x);
+            }
+        }
+        segments
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+// Adapter returned by IterExt::batches
+struct Batches<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I: Iterator> Iterator for Batches<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch: Vec<_> = self.iter.by_ref().take(self.n).collect();
+        if batch.is_empty() { None } else { Some(batch) }
+    }
+}
+
+// Adapter returned by IterExt::dedup_consecutive
+struct DedupConsecutive<I: Iterator> {
+    iter: I,
+    last: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupConsecutive<I>
+where
+    I: Iterator,
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for x in self.iter.by_ref() {
+            if self.last.as_ref() != Some(&x) {
+                self.last = Some(x.clone());
+                return Some(x);
+            }
+        }
+        None
+    }
+}
+
+// Adapter returned by IterExt::pad_to
+struct PadTo<I: Iterator> {
+    iter: std::iter::Fuse<I>,
+    len: usize,
+    produced: usize,
+    filler: I::Item,
+}
+
+impl<I> Iterator for PadTo<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.iter.next() {
+            Some(x) => x,
+            None if self.produced < self.len => self.filler.clone(),
+            None => return None,
+        };
+        self.produced += 1;
+        Some(item)
+    }
+}
+
+// Adapter returned by IterExt::sliding_max.
+// The deque holds (index, value) pairs with decreasing values, so the front
+// is always the maximum of the current window: O(1) amortized per item
+// instead of rescanning the whole window.
+struct SlidingMax<I, T> {
+    iter: I,
+    window: usize,
+    index: usize,
+    deque: VecDeque<(usize, T)>,
+}
+
+impl<I, T> Iterator for SlidingMax<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Ord + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        for x in self.iter.by_ref() {
+            let i = self.index;
+            self.index += 1;
+
+            while self.deque.back().is_some_and(|&(_, v)| v <= x) {
+                self.deque.pop_back();
+            }
+            self.deque.push_back((i, x));
+            if self.deque.front().is_some_and(|&(j, _)| j + self.window <= i) {
+                self.deque.pop_front();
+            }
+
+            if i + 1 >= self.window {
+                return self.deque.front().map(|&(_, v)| v);
+            }
+        }
+        None
+    }
+}
+
+// Adapter returned by IterExt::moving_average.
+// Keeps the last `window` values and their running sum.
+struct MovingAverage<I> {
+    iter: I,
+    window: usize,
+    buf: VecDeque<f64>,
+    sum: f64,
+}
+
+impl<I: Iterator<Item = f64>> Iterator for MovingAverage<I> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        for x in self.iter.by_ref() {
+            self.buf.push_back(x);
+            self.sum += x;
+            if self.buf.len() > self.window {
+                self.sum -= self.buf.pop_front().unwrap();
+            }
+            if self.buf.len() == self.window {
+                return Some(self.sum / self.window as f64);
+            }
+        }
+        None
+    }
+}
+
+// Adapter returned by IterExt::iter_pairs
+struct IterPairs<I: Iterator> {
+    iter: I,
+    prev: Option<I::Item>,
+}
+
+impl<I> Iterator for IterPairs<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.prev.is_none() {
+            self.prev = Some(self.iter.next()?);
+        }
+        let cur = self.iter.next()?;
+        let prev = self.prev.replace(cur.clone())?;
+        Some((prev, cur))
+    }
+}
+
+// Adapter returned by IterExt::group_runs_len
+struct GroupRunsLen<I: Iterator> {
+    iter: std::iter::Peekable<I>,
+}
+
+impl<I> Iterator for GroupRunsLen<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let first = self.iter.next()?;
+        let mut len = 1;
+        while self.iter.next_if(|x| *x == first).is_some() {
+            len += 1;
+        }
+        Some(len)
+    }
+}
+
+// Adapter returned by IterExt::chunk_by_sum
+struct ChunkBySum<I: Iterator> {
+    iter: std::iter::Peekable<I>,
+    limit: i64,
+}
+
+impl<I: Iterator<Item = i64>> Iterator for ChunkBySum<I> {
+    type Item = Vec<i64>;
+
+    fn next(&mut self) -> Option<Vec<i64>> {
+        let first = self.iter.next()?;
+        let mut sum = first;
+        let mut chunk = vec![first];
+        let limit = self.limit;
+        while let Some(x) = self.iter.next_if(|&x| sum.checked_add(x).is_some_and(|s| s <= limit)) {
+            sum += x;
+            chunk.push(x);
+        }
+        Some(chunk)
+    }
+}
+
+// Adapter returned by IterExt::scan_state.
+// Like std's Scan it is not fused: once f returns None, stop calling next.
+struct Scanner<I, S, F> {
+    iter: I,
+    state: S,
+    f: F,
+}
+
+impl<I, S, B, F> Iterator for Scanner<I, S, F>
+where
+    I: Iterator,
+    F: FnMut(&mut S, I::Item) -> Option<B>,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<B> {
+        let x = self.iter.next()?;
+        (self.f)(&mut self.state, x)
+    }
+}
+
+fn test_sum_squared() {
+    assert_eq!((1..=3).sum_squared(), 14);
+    assert_eq!((1..=10).sum_squared(), 385);
+}
+
+fn test_tally() {
+    let counts = "aabbbc".chars().tally();
+    assert_eq!(counts.len(), 3);
+    assert_eq!(counts[&'a'], 2);
+    assert_eq!(counts[&'b'], 3);
+    assert_eq!(counts[&'c'], 1);
+}
+
+fn test_min_max() {
+    assert_eq!([3, 1, 4, 1, 5, 9, 2].into_iter().min_max(), Some((1, 9)));
+    assert_eq!(std::iter::empty::<i32>().min_max(), None);
+}
+
+// Fold each chunk on its own, then combine the partial sums.
+// Addition is associative, so every chunk could be handed to its own thread
+// (e.g. std::thread::scope) without changing the result.
+// Panics if chunk == 0, like slice::chunks.
+fn chunked_sum(data: &[i64], chunk: usize) -> i64 {
+    let partials: Vec<i64> = data
+        .chunks(chunk)
+        .map(|c| c.iter().sum::<i64>())
+        .collect();
+    partials.iter().sum()
+}
+
+fn test_chunked_sum() {
+    let data: Vec<i64> = (-20..=50).collect();
+    let expected: i64 = data.iter().sum();
+    for chunk in [1, 2, 3, 7, 64, 100] {
+        assert_eq!(chunked_sum(&data, chunk), expected);
+    }
+    assert_eq!(chunked_sum(&[], 4), 0);
+}
+
+// Summary of a sequence, built with a single fold (see [42])
+#[derive(Debug)]
+struct Stats {
+    count: u64,
+    sum: i64,
+    min: i64,
+    max: i64,
+}
+
+impl Stats {
+    // None for empty input: there is no min/max to report
+    fn from_iter(iter: impl IntoIterator<Item = i64>) -> Option<Stats> {
+        iter.into_iter().fold(None, |acc, x| match acc {
+            None => Some(Stats { count: 1, sum: x, min: x, max: x }),
+            Some(mut s) => {
+                s.count += 1;
+                s.sum += x;
+                s.min = s.min.min(x);
+                s.max = s.max.max(x);
+                Some(s)
+            }
+        })
+    }
+
+    fn mean(&self) -> f64 {
+        self.sum as f64 / self.count as f64
+    }
+}
+
+fn test_stats() {
+    let stats = Stats::from_iter([4, -2, 10, 8]).unwrap();
+    assert_eq!(stats.count, 4);
+    assert_eq!(stats.sum, 20);
+    assert_eq!(stats.min, -2);
+    assert_eq!(stats.max, 10);
+    assert_eq!(stats.mean(), 5.0);
+    assert!(Stats::from_iter(Vec::new()).is_none());
+}
+
+// Like zip (see [19]) but over three iterators; stops at the shortest one
+fn zip3<A, B, C>(a: A, b: B, c: C) -> impl Iterator<Item = (A::Item, B::Item, C::Item)>
+where
+    A: IntoIterator,
+    B: IntoIterator,
+    C: IntoIterator,
+{
+    a.into_iter()
+        .zip(b.into_iter().zip(c))
+        .map(|(x, (y, z))| (x, y, z))
+}
+
+fn test_zip3() {
+    let triples: Vec<_> = zip3(0..5, 10..13, 20..30).collect();
+    assert_eq!(triples, vec![(0, 10, 20), (1, 11, 21), (2, 12, 22)]);
+}
+
+// All (a, b) pairs, a-major: b is iterated in the inner loop
+fn cartesian_product<T: Clone, U: Clone>(a: &[T], b: &[U]) -> Vec<(T, U)> {
+    a.iter()
+        .flat_map(|x| b.iter().map(move |y| (x.clone(), y.clone())))
+        .collect()
+}
+
+fn test_cartesian_product() {
+    let pairs = cartesian_product(&[1, 2], &['x', 'y']);
+    assert_eq!(pairs, vec![(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]);
+    assert!(cartesian_product::<i32, char>(&[], &['x']).is_empty());
+}
+
+// True if f panics; the default hook is silenced so the demo output stays clean
+fn panics<F: FnOnce() + std::panic::UnwindSafe>(f: F) -> bool {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(f);
+    std::panic::set_hook(hook);
+    result.is_err()
+}
+
+fn test_batches() {
+    let batches: Vec<Vec<i32>> = (1..=5).batches(2).collect();
+    assert_eq!(batches, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    assert!(panics(|| {
+        (1..=5).batches(0);
+    }));
+}
+
+fn test_dedup_consecutive() {
+    let v: Vec<i32> = [1, 1, 2, 3, 3, 3, 1].into_iter().dedup_consecutive().collect();
+    assert_eq!(v, vec![1, 2, 3, 1]);
+}
+
+fn test_pad_to() {
+    let v: Vec<i32> = [1, 2].into_iter().pad_to(4, 0).collect();
+    assert_eq!(v, vec![1, 2, 0, 0]);
+    let v: Vec<i32> = [1, 2, 3].into_iter().pad_to(2, 0).collect();
+    assert_eq!(v, vec![1, 2, 3]);
+}
+
+fn test_sliding_max() {
+    let v: Vec<i32> = [1, 3, 2, 5, 4].into_iter().sliding_max(2).collect();
+    assert_eq!(v, vec![3, 3, 5, 5]);
+    let v: Vec<i32> = [9, 1, 2, 3, 0].into_iter().sliding_max(3).collect();
+    assert_eq!(v, vec![9, 3, 3]);
+    assert_eq!([1, 2].into_iter().sliding_max(3).count(), 0);
+}
+
+// output[i] is the sum of data[0..=i]
+fn prefix_sums(data: &[i64]) -> Vec<i64> {
+    let mut total = 0;
+    data.iter()
+        .map(|x| {
+            total += x;
+            total
+        })
+        .collect()
+}
+
+fn test_prefix_sums() {
+    assert_eq!(prefix_sums(&[1, 2, 3]), vec![1, 3, 6]);
+    assert!(prefix_sums(&[]).is_empty());
+
+    let data = [5, -2, 7, 0, 3];
+    let lazy: Vec<i64> = data.into_iter().cumulative_sum().collect();
+    assert_eq!(lazy, prefix_sums(&data));
+}
+
+// Inverse of zip3: split triples into three vectors
+fn unzip3<A, B, C>(iter: impl Iterator<Item = (A, B, C)>) -> (Vec<A>, Vec<B>, Vec<C>) {
+    let mut out = (Vec::new(), Vec::new(), Vec::new());
+    for (a, b, c) in iter {
+        out.0.push(a);
+        out.1.push(b);
+        out.2.push(c);
+    }
+    out
+}
+
+fn test_unzip3() {
+    let (nums, chars, flags) = unzip3([(1, 'a', true), (2, 'b', false)].into_iter());
+    assert_eq!(nums, vec![1, 2]);
+    assert_eq!(chars, vec!['a', 'b']);
+    assert_eq!(flags, vec![true, false]);
+}
+
+// Format every item and join with sep, without the
+// map(to_string).collect::<Vec<_>>().join() round trip from [1]
+fn join_display<T: Display>(items: &[T], sep: &str) -> String {
+    let mut out = String::new();
+    for (i, x) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(sep);
+        }
+        write!(out, "{x}").unwrap();
+    }
+    out
+}
+
+fn test_join_display() {
+    assert_eq!(join_display(&[1, 2, 3], ", "), "1, 2, 3");
+    assert_eq!(join_display(&["A", "B", "C"], " "), "A B C");
+    assert_eq!(join_display::<i32>(&[], ", "), "");
+}
+
+fn test_take_every() {
+    let v: Vec<i32> = (0..10).take_every(3).collect();
+    assert_eq!(v, vec![0, 3, 6, 9]);
+    assert!(panics(|| {
+        let _ = (0..10).take_every(0);
+    }));
+}
+
+// Named shorthand for items.iter().filter(pred).count()
+fn count_matching<T, P: Fn(&T) -> bool>(items: &[T], pred: P) -> usize {
+    items.iter().filter(|x| pred(x)).count()
+}
+
+fn test_count_matching() {
+    assert_eq!(count_matching(&[1, 2, 3, 4, 5, 6, 7], |x| x % 2 == 0), 3);
+    assert_eq!(count_matching(&[1, 3, 5], |x| x % 2 == 0), 0);
+}
+
+// Both ends of a slice; a single element is both first and last
+fn first_and_last<T: Copy>(items: &[T]) -> Option<(T, T)> {
+    Some((*items.first()?, *items.last()?))
+}
+
+fn test_first_and_last() {
+    assert_eq!(first_and_last::<i32>(&[]), None);
+    assert_eq!(first_and_last(&[7]), Some((7, 7)));
+    assert_eq!(first_and_last(&[1, 2, 3, 4]), Some((1, 4)));
+}
+
+// The index trick from [83] as a reusable iterator: cycles over the slice
+// forever, so pair it with take() (or any other short-circuiting adapter).
+struct Circular<'a, T> {
+    data: &'a [T],
+    pos: usize,
+}
+
+impl<'a, T> Circular<'a, T> {
+    // Panics on an empty slice: there would be nothing to cycle over
+    fn new(data: &'a [T]) -> Self {
+        assert!(!data.is_empty(), "cannot cycle over an empty slice");
+        Circular { data, pos: 0 }
+    }
+}
+
+impl<'a, T> Iterator for Circular<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = &self.data[self.pos];
+        self.pos = (self.pos + 1) % self.data.len();
+        Some(item)
+    }
+}
+
+fn test_circular() {
+    let v: Vec<i32> = Circular::new(&[1, 2, 3]).take(7).copied().collect();
+    assert_eq!(v, vec![1, 2, 3, 1, 2, 3, 1]);
+    assert!(panics(|| {
+        Circular::<i32>::new(&[]);
+    }));
+}
+
+// [45] as a reusable function: flatten one level, then scale
+fn flatten_and_scale(nested: Vec<Vec<i32>>, factor: i32) -> Vec<i32> {
+    nested.into_iter().flatten().map(|x| x * factor).collect()
+}
+
+fn test_flatten_and_scale() {
+    let nested = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    assert_eq!(flatten_and_scale(nested, 2), vec![2, 4, 6, 8, 10, 12]);
+}
+
+// Three-way partition around a pivot: (less, equal, greater).
+// The building block of a quicksort that copes well with duplicates.
+fn partition_three<T: Ord + Copy>(items: &[T], pivot: T) -> (Vec<T>, Vec<T>, Vec<T>) {
+    let mut out = (Vec::new(), Vec::new(), Vec::new());
+    for &x in items {
+        match x.cmp(&pivot) {
+            Ordering::Less => out.0.push(x),
+            Ordering::Equal => out.1.push(x),
+            Ordering::Greater => out.2.push(x),
+        }
+    }
+    out
+}
+
+fn test_partition_three() {
+    let (less, equal, greater) = partition_three(&[3, 1, 3, 5, 2], 3);
+    assert_eq!(less, vec![1, 2]);
+    assert_eq!(equal, vec![3, 3]);
+    assert_eq!(greater, vec![5]);
+}
+
+// windows(size).map(f).collect() in one call (see [47]).
+// Panics if size == 0, like slice::windows.
+fn windows_map<T, U, F: Fn(&[T]) -> U>(data: &[T], size: usize, f: F) -> Vec<U> {
+    data.windows(size).map(f).collect()
+}
+
+fn test_windows_map() {
+    let avgs = windows_map(&[1.0, 2.0, 3.0, 4.0], 2, |w| w.iter().sum::<f64>() / w.len() as f64);
+    assert_eq!(avgs, vec![1.5, 2.5, 3.5]);
+    assert!(windows_map(&[1, 2], 3, |w| w[0]).is_empty());
+}
+
+fn test_positions() {
+    let v: Vec<usize> = [10, 21, 30, 41].into_iter().positions(|x| x % 2 == 0).collect();
+    assert_eq!(v, vec![0, 2]);
+}
+
+// Strict version of [46]: collecting into Result stops at the first bad
+// token instead of silently replacing it with 0
+fn try_collect_ints<'a>(tokens: impl Iterator<Item = &'a str>) -> Result<Vec<i32>, String> {
+    tokens
+        .map(|t| t.parse::<i32>().map_err(|_| format!("invalid integer: {t:?}")))
+        .collect()
+}
+
+fn test_try_collect_ints() {
+    assert_eq!(try_collect_ints("1,2,3".split(",")), Ok(vec![1, 2, 3]));
+    assert_eq!(
+        try_collect_ints("1,2,3,g,h".split(",")),
+        Err("invalid integer: \"g\"".to_string())
+    );
+}
+
+// reduce(f).unwrap_or_default() from [20b], e.g. an empty range sums to 0
+fn reduce_or_default<T: Default, F: Fn(T, T) -> T>(iter: impl IntoIterator<Item = T>, f: F) -> T {
+    iter.into_iter().reduce(f).unwrap_or_default()
+}
+
+fn test_reduce_or_default() {
+    assert_eq!(reduce_or_default(1..=4, |acc, x| acc + x), 10);
+    assert_eq!(reduce_or_default(std::iter::empty::<i32>(), |acc, x| acc + x), 0);
+    assert_eq!(reduce_or_default(Vec::<String>::new(), |a, b| a + &b), "");
+}
+
+// The sentence fold from [20a], but the separator goes *before* every word
+// except the first, so there is no trailing space
+fn join_words(words: &[&str]) -> String {
+    words.iter().enumerate().fold(String::new(), |mut acc, (i, w)| {
+        if i > 0 {
+            acc.push(' ');
+        }
+        acc.push_str(w);
+        acc
+    })
+}
+
+fn test_join_words() {
+    assert_eq!(join_words(&["Hello", "world"]), "Hello world");
+    assert_eq!(join_words(&["Hello", "world", "from", "space"]), "Hello world from space");
+    assert_eq!(join_words(&[]), "");
+}
+
+// Merge two ascending iterators by peeking at both heads (see [4])
+// and always taking the smaller one
+fn merge_sorted<T: Ord>(a: impl Iterator<Item = T>, b: impl Iterator<Item = T>) -> Vec<T> {
+    let mut a = a.peekable();
+    let mut b = b.peekable();
+    let mut out = Vec::new();
+    loop {
+        let take_a = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => x <= y,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+        let next = if take_a { a.next() } else { b.next() };
+        out.extend(next);
+    }
+    out
+}
+
+fn test_merge_sorted() {
+    let merged = merge_sorted([1, 3, 5].into_iter(), [2, 4, 6].into_iter());
+    assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+    let merged = merge_sorted([1, 10].into_iter(), [2, 3, 4, 11, 12].into_iter());
+    assert_eq!(merged, vec![1, 2, 3, 4, 10, 11, 12]);
+    assert_eq!(merge_sorted(std::iter::empty(), [7].into_iter()), vec![7]);
+}
+
+fn test_filter_map_indexed() {
+    let tokens = ["10", "hi", "x", "20", "30", "40"];
+    let v: Vec<i32> = tokens
+        .into_iter()
+        .filter_map_indexed(|(i, t)| if i % 2 == 0 { t.parse().ok() } else { None })
+        .collect();
+    assert_eq!(v, vec![10, 30]);
+}
+
+// [3] generalized: chain any number of iterators, in order
+fn chain_all<I: IntoIterator>(iters: Vec<I>) -> impl Iterator<Item = I::Item> {
+    iters.into_iter().flatten()
+}
+
+fn test_chain_all() {
+    let v: Vec<i32> = chain_all(vec![1..3, 10..12, 20..21]).collect();
+    assert_eq!(v, vec![1, 2, 10, 11, 20]);
+    assert_eq!(chain_all(Vec::<Vec<i32>>::new()).count(), 0);
+}
+
+fn test_nth_or_last() {
+    assert_eq!((10..15).nth_or_last(2), Some(12));
+    assert_eq!((10..15).nth_or_last(9), Some(14));
+    assert_eq!(std::iter::empty::<i32>().nth_or_last(0), None);
+}
+
+// Split before the first item matching pred (it starts the second half).
+// Unlike partition ([17]) the original order is kept on both sides.
+// With no match everything ends up in the first half.
+fn split_at_predicate<T: Clone, P: Fn(&T) -> bool>(items: &[T], pred: P) -> (Vec<T>, Vec<T>) {
+    let mid = items.iter().position(pred).unwrap_or(items.len());
+    let (head, tail) = items.split_at(mid);
+    (head.to_vec(), tail.to_vec())
+}
+
+fn test_split_at_predicate() {
+    assert_eq!(split_at_predicate(&[1, 2, 3, 4], |x| x % 2 == 0), (vec![1], vec![2, 3, 4]));
+    assert_eq!(split_at_predicate(&[1, 3], |x| x % 2 == 0), (vec![1, 3], vec![]));
+}
+
+fn test_running_min_max() {
+    let mins: Vec<i32> = [3, 1, 2].into_iter().running_min().collect();
+    assert_eq!(mins, vec![3, 1, 1]);
+    let maxs: Vec<i32> = [3, 1, 2].into_iter().running_max().collect();
+    assert_eq!(maxs, vec![3, 3, 3]);
+}
+
+// Count values per equal-width bin over [min, max].
+// Values outside the range (and NaN) are dropped, not clamped; max itself
+// falls into the last bin. Panics if bins == 0 or max <= min.
+fn histogram(values: &[f64], bins: usize, min: f64, max: f64) -> Vec<usize> {
+    assert!(bins > 0, "need at least one bin");
+    assert!(max > min, "empty range");
+    let width = (max - min) / bins as f64;
+    let mut counts = vec![0; bins];
+    for &v in values.iter().filter(|&&v| v >= min && v <= max) {
+        let bin = (((v - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+    counts
+}
+
+fn test_histogram() {
+    assert_eq!(histogram(&[0.1, 0.5, 0.9], 2, 0.0, 1.0), vec![1, 2]);
+    assert_eq!(histogram(&[-1.0, 0.0, 1.0, 2.0], 4, 0.0, 1.0), vec![1, 0, 0, 1]);
+}
+
+fn test_enumerate_from() {
+    let v: Vec<(usize, &char)> = ['a', 'b'].iter().enumerate_from(1).collect();
+    assert_eq!(v, vec![(1, &'a'), (2, &'b')]);
+}
+
+// sum() that reports overflow: try_fold stops at the first None
+fn checked_sum(mut iter: impl Iterator<Item = i64>) -> Option<i64> {
+    iter.try_fold(0i64, |acc, x| acc.checked_add(x))
+}
+
+fn test_checked_sum() {
+    assert_eq!(checked_sum(1..=100), Some(5050));
+    assert_eq!(checked_sum([i64::MAX, 1].into_iter()), None);
+}
+
+// start, start + step, ... up to and including end when a step lands on it.
+// A negative step counts down. Panics if step == 0.
+fn step_range_inclusive(start: i64, end: i64, step: i64) -> Vec<i64> {
+    assert!(step != 0, "step must be non-zero");
+    let mut out = Vec::new();
+    let mut x = start;
+    while (step > 0 && x <= end) || (step < 0 && x >= end) {
+        out.push(x);
+        match x.checked_add(step) {
+            Some(next) => x = next,
+            None => break,
+        }
+    }
+    out
+}
+
+fn test_step_range_inclusive() {
+    assert_eq!(step_range_inclusive(0, 10, 2), vec![0, 2, 4, 6, 8, 10]);
+    assert_eq!(step_range_inclusive(0, 9, 2), vec![0, 2, 4, 6, 8]);
+    assert_eq!(step_range_inclusive(10, 0, -5), vec![10, 5, 0]);
+    assert!(step_range_inclusive(5, 0, 1).is_empty());
+    assert!(panics(|| {
+        step_range_inclusive(0, 10, 0);
+    }));
+}
+
+// Swap rows and columns. Ragged input has no transpose, so it is an Err
+// naming the first row whose length differs from row 0.
+fn transpose<T: Clone>(matrix: Vec<Vec<T>>) -> Result<Vec<Vec<T>>, String> {
+    let cols = matrix.first().map_or(0, |row| row.len());
+    if let Some(i) = matrix.iter().position(|row| row.len() != cols) {
+        return Err(format!("row {i} has {} columns, expected {cols}", matrix[i].len()));
+    }
+    Ok((0..cols)
+        .map(|c| matrix.iter().map(|row| row[c].clone()).collect())
+        .collect())
+}
+
+fn test_transpose() {
+    let m = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    assert_eq!(transpose(m), Ok(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+    let ragged = vec![vec![1, 2], vec![3]];
+    assert_eq!(transpose(ragged), Err("row 1 has 1 columns, expected 2".to_string()));
+}
+
+fn test_moving_average() {
+    let v: Vec<f64> = [1.0, 2.0, 3.0, 4.0].into_iter().moving_average(2).collect();
+    assert_eq!(v, vec![1.5, 2.5, 3.5]);
+    assert!(panics(|| {
+        [1.0].into_iter().moving_average(0);
+    }));
+}
+
+fn test_accumulate_while() {
+    let v: Vec<u32> = (1..).accumulate_while(0, |acc, x| acc + x, |&s| s <= 10).collect();
+    assert_eq!(v, vec![1, 3, 6, 10]);
+}
+
+// Bounded buffer built on the same modulo index trick as [83].
+// head is the slot of the oldest element; once full, push overwrites it.
+struct RingBuffer<T> {
+    data: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+    cap: usize,
+}
+
+impl<T> RingBuffer<T> {
+    // Panics if cap == 0: a zero-slot ring cannot hold anything
+    fn with_capacity(cap: usize) -> Self {
+        assert!(cap > 0, "capacity must be non-zero");
+        RingBuffer {
+            data: (0..cap).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+            cap,
+        }
+    }
+
+    // Append, evicting the oldest element when the buffer is full
+    fn push(&mut self, value: T) {
+        let slot = (self.head + self.len) % self.cap;
+        self.data[slot] = Some(value);
+        if self.len == self.cap {
+            self.head = (self.head + 1) % self.cap;
+        } else {
+            self.len += 1;
+        }
+    }
+
+    // Oldest to newest
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).filter_map(move |i| self.data[(self.head + i) % self.cap].as_ref())
+    }
+}
+
+fn test_ring_buffer() {
+    let mut ring = RingBuffer::with_capacity(3);
+    ring.push(1);
+    ring.push(2);
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+    ring.push(3);
+    ring.push(4);
+    ring.push(5);
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+}
+
+fn test_iter_pairs() {
+    let v: Vec<(i32, i32)> = (1..=4).iter_pairs().collect();
+    assert_eq!(v, vec![(1, 2), (2, 3), (3, 4)]);
+    assert_eq!([7].into_iter().iter_pairs().count(), 0);
+}
+
+fn test_find_index() {
+    assert_eq!((1..=10).find_index(|x| x % 3 == 0), Some(2));
+    assert_eq!((1..=10).find_index(|x| *x > 10), None);
+}
+
+fn test_take_until() {
+    let v: Vec<i32> = (1..).take_until(|&x| x > 3).collect();
+    assert_eq!(v, vec![1, 2, 3, 4]);
+    let v: Vec<i32> = (1..3).take_until(|&x| x > 3).collect();
+    assert_eq!(v, vec![1, 2]);
+}
+
+fn test_group_runs_len() {
+    let v: Vec<usize> = [1, 1, 2, 3, 3, 3].into_iter().group_runs_len().collect();
+    assert_eq!(v, vec![2, 1, 3]);
+}
+
+fn test_scan_sum() {
+    let v: Vec<(i64, i64)> = [1, 2, 3].into_iter().scan_sum().collect();
+    assert_eq!(v, vec![(1, 1), (2, 3), (3, 6)]);
+}
+
+// Function composition: compose(f, g)(x) == g(f(x))
+fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+fn test_compose() {
+    let inc_then_square = compose(|x: i32| x + 1, |x| x * x);
+    let results: Vec<i32> = [0, 1, 2, -3].into_iter().map(&inc_then_square).collect();
+    assert_eq!(results, vec![1, 4, 9, 4]);
+
+    let double_to_string = compose(|x: i32| x * 2, |x| x.to_string());
+    assert_eq!(double_to_string(21), "42");
+}
+
+// Naive recursive Fibonacci from [48]: O(2^n) calls, kept for comparison
+fn fib(n: u32) -> u32 {
+    if n < 2 { n } else { fib(n - 2) + fib(n - 1) }
+}
+
+// Bottom-up Fibonacci: O(n) time, O(1) memory.
+// fib(93) is the largest value that fits in u64; panics above that.
+fn fib_memo(n: u32) -> u64 {
+    assert!(n <= 93, "fib({n}) overflows u64");
+    if n == 0 {
+        return 0;
+    }
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 1..n {
+        (a, b) = (b, a + b);
+    }
+    b
+}
+
+fn test_fib_memo() {
+    for n in 0..20 {
+        assert_eq!(fib_memo(n), fib(n) as u64);
+    }
+    assert_eq!(fib_memo(50), 12_586_269_025);
+    assert_eq!(fib_memo(93), 12_200_160_415_121_876_738);
+    assert!(panics(|| {
+        fib_memo(94);
+    }));
+}
+
+// [43] without silent overflow: None once n! no longer fits in u64 (n > 20)
+fn factorial_checked(n: u32) -> Option<u64> {
+    (1..=n as u64).try_fold(1u64, |acc, x| acc.checked_mul(x))
+}
+
+fn test_factorial_checked() {
+    assert_eq!(factorial_checked(0), Some(1));
+    assert_eq!(factorial_checked(10), Some(3628800));
+    assert_eq!(factorial_checked(20), Some(2_432_902_008_176_640_000));
+    assert_eq!(factorial_checked(21), None);
+    assert_eq!(factorial_checked(25), None);
+}
+
+// Like chunks in [10], but the short tail is dropped (slice::chunks_exact).
+// Panics if size == 0.
+fn chunks_exact_vec<T>(data: &[T], size: usize) -> Vec<&[T]> {
+    data.chunks_exact(size).collect()
+}
+
+fn test_chunks_exact_vec() {
+    let data = [1, 2, 3, 4, 5];
+    let chunks = chunks_exact_vec(&data, 2);
+    assert_eq!(chunks, vec![&[1, 2][..], &[3, 4][..]]);
+}
+
+// reduce ([20b]) with a fallible step: the first Err stops the reduction.
+// Ok(None) means the input was empty.
+fn try_reduce<T, E, F: Fn(T, T) -> Result<T, E>>(
+    iter: impl IntoIterator<Item = T>,
+    f: F,
+) -> Result<Option<T>, E> {
+    let mut iter = iter.into_iter();
+    let Some(first) = iter.next() else {
+        return Ok(None);
+    };
+    iter.try_fold(first, f).map(Some)
+}
+
+fn test_try_reduce() {
+    let add = |a: i32, b: i32| a.checked_add(b).ok_or("overflow");
+    assert_eq!(try_reduce([1, 2, 3], add), Ok(Some(6)));
+    assert_eq!(try_reduce(Vec::new(), add), Ok(None));
+    assert_eq!(try_reduce([1, i32::MAX, 3], add), Err("overflow"));
+}
+
+fn test_dedup_approx() {
+    let v: Vec<f64> = [1.0, 1.0001, 2.0].into_iter().dedup_approx(0.001).collect();
+    assert_eq!(v, vec![1.0, 2.0]);
+}
+
+// [8] in two dimensions: ((row, col), value) for every cell.
+// Ragged rows are fine, each row contributes its own length.
+fn enumerate_matrix<T: Clone>(matrix: &[Vec<T>]) -> Vec<((usize, usize), T)> {
+    matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(r, row)| row.iter().enumerate().map(move |(c, x)| ((r, c), x.clone())))
+        .collect()
+}
+
+fn test_enumerate_matrix() {
+    let m = vec![vec!['a', 'b'], vec!['c', 'd']];
+    assert_eq!(
+        enumerate_matrix(&m),
+        vec![((0, 0), 'a'), ((0, 1), 'b'), ((1, 0), 'c'), ((1, 1), 'd')]
+    );
+    let ragged = vec![vec![1], vec![2, 3]];
+    assert_eq!(enumerate_matrix(&ragged), vec![((0, 0), 1), ((1, 0), 2), ((1, 1), 3)]);
+}
+
+fn test_count_runs() {
+    assert_eq!([1, 1, 2, 2, 1].into_iter().count_runs(), 3);
+    assert_eq!(std::iter::empty::<i32>().count_runs(), 0);
+    assert_eq!([5, 5, 5].into_iter().count_runs(), 1);
+}
+
+fn test_pairwise_diff() {
+    let v: Vec<i64> = [1, 3, 6, 10].into_iter().pairwise_diff().collect();
+    assert_eq!(v, vec![2, 3, 4]);
+    assert_eq!([5].into_iter().pairwise_diff().count(), 0);
+}
+
+// The n largest items, largest first, in O(len * log n) time and O(n)
+// memory: a min-heap (Reverse) of size n whose top is the weakest keeper
+fn top_n<T: Ord + Clone>(items: impl Iterator<Item = T>, n: usize) -> Vec<T> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut heap = BinaryHeap::with_capacity(n + 1);
+    for x in items {
+        heap.push(Reverse(x));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|Reverse(x)| x).collect()
+}
+
+fn test_top_n() {
+    assert_eq!(top_n([5, 1, 9, 3, 7].into_iter(), 3), vec![9, 7, 5]);
+    assert_eq!(top_n([2, 1].into_iter(), 5), vec![2, 1]);
+    assert!(top_n([2, 1].into_iter(), 0).is_empty());
+}
+
+fn test_with_previous() {
+    let v: Vec<_> = [10, 20, 30].into_iter().with_previous().collect();
+    assert_eq!(v, vec![(None, 10), (Some(10), 20), (Some(20), 30)]);
+}
+
+// Sum of values[i] * weights[i]. zip ([19]) would silently stop at the
+// shorter slice, so mismatched lengths are reported as None instead.
+fn weighted_sum(values: &[f64], weights: &[f64]) -> Option<f64> {
+    if values.len() != weights.len() {
+        return None;
+    }
+    Some(values.iter().zip(weights).map(|(v, w)| v * w).sum())
+}
+
+fn test_weighted_sum() {
+    assert_eq!(weighted_sum(&[1.0, 2.0, 3.0], &[0.5, 0.25, 1.0]), Some(4.0));
+    assert_eq!(weighted_sum(&[1.0, 2.0], &[1.0]), None);
+}
+
+// Set semantics with insertion order kept: the Vec remembers the order,
+// the HashSet answers "seen before?" in O(1)
+struct OrderedSet<T: Eq + Hash> {
+    items: Vec<T>,
+    seen: HashSet<T>,
+}
+
+impl<T: Eq + Hash + Clone> OrderedSet<T> {
+    fn new() -> Self {
+        OrderedSet { items: Vec::new(), seen: HashSet::new() }
+    }
+
+    // Returns false (and changes nothing) if value was already present
+    fn insert(&mut self, value: T) -> bool {
+        if !self.seen.insert(value.clone()) {
+            return false;
+        }
+        self.items.push(value);
+        true
+    }
+
+    fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+fn test_ordered_set() {
+    let mut set = OrderedSet::new();
+    for x in [1, 2, 1, 3] {
+        set.insert(x);
+    }
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert!(!set.insert(2));
+}
+
+// Named version of filter_map(|x| x) from [12]: drop Nones, unwrap Somes
+fn flatten_options<T>(items: Vec<Option<T>>) -> Vec<T> {
+    items.into_iter().flatten().collect()
+}
+
+fn test_flatten_options() {
+    assert_eq!(flatten_options(vec![Some(1), None, Some(3)]), vec![1, 3]);
+    assert!(flatten_options::<i32>(vec![None, None]).is_empty());
+}
+
+// Keep both sides of a fallible parse: successes and failures, each in order
+fn partition_results<T, E>(items: impl Iterator<Item = Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for item in items {
+        match item {
+            Ok(x) => oks.push(x),
+            Err(e) => errs.push(e),
+        }
+    }
+    (oks, errs)
+}
+
+fn test_partition_results() {
+    let parsed = ["10", "hi", "20", "x"].into_iter().map(|t| t.parse::<i32>().map_err(|_| t));
+    let (nums, bad) = partition_results(parsed);
+    assert_eq!(nums, vec![10, 20]);
+    assert_eq!(bad, vec!["hi", "x"]);
+}
+
+fn test_chunk_by_sum() {
+    let v: Vec<Vec<i64>> = [4, 3, 2, 5].into_iter().chunk_by_sum(6).collect();
+    assert_eq!(v, vec![vec![4], vec![3, 2], vec![5]]);
+    let v: Vec<Vec<i64>> = [9, 1].into_iter().chunk_by_sum(6).collect();
+    assert_eq!(v, vec![vec![9], vec![1]]);
+    let v: Vec<Vec<i64>> = [i64::MAX - 1, 5].into_iter().chunk_by_sum(i64::MAX).collect();
+    assert_eq!(v, vec![vec![i64::MAX - 1], vec![5]]);
+}
+
+fn test_product_checked() {
+    assert_eq!((1..=10).product_checked(), Some(3628800));
+    assert_eq!([i64::MAX, 2].into_iter().product_checked(), None);
+}
+
+// zip(a, b).map(f) in one step; stops at the shorter iterator
+fn zip_with<A, B, C, F: Fn(A, B) -> C>(
+    a: impl Iterator<Item = A>,
+    b: impl Iterator<Item = B>,
+    f: F,
+) -> Vec<C> {
+    a.zip(b).map(|(x, y)| f(x, y)).collect()
+}
+
+fn test_zip_with() {
+    let sums = zip_with([1, 2, 3].into_iter(), [10, 20, 30].into_iter(), |x, y| x + y);
+    assert_eq!(sums, vec![11, 22, 33]);
+    let products = zip_with(1..10, [2, 3].into_iter(), |x, y| x * y);
+    assert_eq!(products, vec![2, 6]);
+}
+
+// True if no element repeats; HashSet::insert returning false means a
+// duplicate, and all() stops right there
+fn all_unique<T: Eq + Hash>(items: &[T]) -> bool {
+    let mut seen = HashSet::with_capacity(items.len());
+    items.iter().all(|x| seen.insert(x))
+}
+
+fn test_all_unique() {
+    assert!(all_unique(&[1, 2, 3, 4]));
+    assert!(!all_unique(&["a", "b", "a"]));
+    assert!(all_unique::<i32>(&[]));
+}
+
+// Modal element and its count, None for an empty slice.
+// Ties go to the element that appears first in the slice.
+fn most_common<T: Eq + Hash + Clone>(items: &[T]) -> Option<(T, usize)> {
+    let counts = items.iter().tally();
+    let mut best: Option<(&T, usize)> = None;
+    for x in items {
+        let n = counts[x];
+        if best.is_none_or(|(_, m)| n > m) {
+            best = Some((x, n));
+        }
+    }
+    best.map(|(x, n)| (x.clone(), n))
+}
+
+fn test_most_common() {
+    assert_eq!(most_common(&[1, 2, 2, 3, 3, 3]), Some((3, 3)));
+    assert_eq!(most_common(&['b', 'a', 'a', 'b']), Some(('b', 2)));
+    assert_eq!(most_common::<i32>(&[]), None);
+}
+
+// Owned counterpart of chunks ([10]): the Vecs can outlive items.
+// Panics if size == 0.
+fn split_chunks<T: Clone>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    items.chunks(size).map(|c| c.to_vec()).collect()
+}
+
+fn test_split_chunks() {
+    assert_eq!(split_chunks(&[1, 2, 3, 4, 5], 2), vec![vec![1, 2], vec![3, 4], vec![5]]);
+}
+
+// Real use of peek ([4]): after seeing a digit, keep pulling characters
+// only while the *next* one is also a digit, so the first non-digit is
+// left in place for the outer loop.
+// A digit run too large for i64 is consumed but skipped, not wrapped.
+fn tokenize_ints(s: &str) -> Vec<i64> {
+    let mut chars = s.chars().peekable();
+    let mut out = Vec::new();
+    while let Some(&c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            chars.next();
+            continue;
+        }
+        let mut n = Some(0i64);
+        while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+            n = n.and_then(|n| n.checked_mul(10)).and_then(|n| n.checked_add(d as i64));
+            chars.next();
+        }
+        out.extend(n);
+    }
+    out
+}
+
+fn test_tokenize_ints() {
+    assert_eq!(tokenize_ints("12 ab 34"), vec![12, 34]);
+    assert_eq!(tokenize_ints("a1b22c333"), vec![1, 22, 333]);
+    assert!(tokenize_ints("no digits").is_empty());
+    assert_eq!(tokenize_ints("id 12345678901234567890 7"), vec![7]);
+    assert_eq!(tokenize_ints("9223372036854775807"), vec![i64::MAX]);
+}
+
+fn test_sum_by() {
+    let words = ["Hello", "world", "from", "space"];
+    assert_eq!(words.iter().sum_by(|s| s.len() as i64), 19);
+}
+
+// Index of the element with the largest / smallest key; ties go to the
+// first occurrence, None for an empty slice
+fn argmax<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<usize> {
+    // max_by_key keeps the *last* maximum, so compare reversed indices too
+    items
+        .iter()
+        .enumerate()
+        .max_by_key(|&(i, x)| (key(x), Reverse(i)))
+        .map(|(i, _)| i)
+}
+
+fn argmin<T, K: Ord, F: Fn(&T) -> K>(items: &[T], key: F) -> Option<usize> {
+    // min_by_key already keeps the first minimum
+    items.iter().enumerate().min_by_key(|&(_, x)| key(x)).map(|(i, _)| i)
+}
+
+fn test_argmax_argmin() {
+    let words = ["hi", "hello", "hey", "howdy"];
+    assert_eq!(argmax(&words, |s| s.len()), Some(1));
+    assert_eq!(argmin(&words, |s| s.len()), Some(0));
+    assert_eq!(argmax::<i32, i32, _>(&[], |&x| x), None);
+}
+
+fn test_repeat_each() {
+    let v: Vec<i32> = [1, 2].into_iter().repeat_each(3).collect();
+    assert_eq!(v, vec![1, 1, 1, 2, 2, 2]);
+    assert_eq!([1, 2].into_iter().repeat_each(0).count(), 0);
+}
+
+// (min, max) of every window of size values (see [47]).
+// f64 is not Ord, so fold with f64::min / f64::max instead of min()/max().
+// Panics if size == 0.
+fn sliding_window_stats(data: &[f64], size: usize) -> Vec<(f64, f64)> {
+    windows_map(data, size, |w| {
+        w.iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| (lo.min(x), hi.max(x)))
+    })
+}
+
+fn test_sliding_window_stats() {
+    let stats = sliding_window_stats(&[1.0, 3.0, 2.0, 5.0], 2);
+    assert_eq!(stats, vec![(1.0, 3.0), (2.0, 3.0), (2.0, 5.0)]);
+}
+
+// Mean of each chunks(size) group; the short final chunk is averaged over
+// its own length. Panics if size == 0, like slice::chunks.
+fn chunk_averages(data: &[f64], size: usize) -> Vec<f64> {
+    data.chunks(size)
+        .map(|c| c.iter().sum::<f64>() / c.len() as f64)
+        .collect()
+}
+
+fn test_chunk_averages() {
+    assert_eq!(chunk_averages(&[1.0, 2.0, 3.0, 4.0, 5.0], 2), vec![1.5, 3.5, 5.0]);
+    assert!(chunk_averages(&[], 3).is_empty());
+}
+
+fn test_find_map_indexed() {
+    let found = [3, 7, 8, 10].into_iter().find_map_indexed(|(i, x)| (x % 2 == 0).then_some((i, x)));
+    assert_eq!(found, Some((2, 8)));
+    assert_eq!([1, 3].into_iter().find_map_indexed(|(i, x)| (x % 2 == 0).then_some((i, x))), None);
+}
+
+fn test_scan_state() {
+    let sums: Vec<i32> = (1..=5)
+        .scan_state(0, |sum, x| {
+            *sum += x;
+            Some(*sum)
+        })
+        .collect();
+    assert_eq!(sums, vec![1, 3, 6, 10, 15]);
+
+    let capped: Vec<i32> = (1..)
+        .scan_state(0, |sum, x| {
+            *sum += x;
+            if *sum > 10 { None } else { Some(*sum) }
+        })
+        .collect();
+    assert_eq!(capped, vec![1, 3, 6, 10]);
+}
+
+// Round-robin over any number of iterators, dropping each one as soon as
+// it runs dry, until all are exhausted
+fn interleave_all<T>(iters: Vec<Box<dyn Iterator<Item = T>>>) -> Vec<T> {
+    let mut queue: VecDeque<_> = iters.into();
+    let mut out = Vec::new();
+    while let Some(mut it) = queue.pop_front() {
+        if let Some(x) = it.next() {
+            out.push(x);
+            queue.push_back(it);
+        }
+    }
+    out
+}
+
+fn test_interleave_all() {
+    let iters: Vec<Box<dyn Iterator<Item = i32>>> =
+        vec![Box::new(1..4), Box::new(10..11), Box::new(20..23)];
+    assert_eq!(interleave_all(iters), vec![1, 10, 20, 2, 21, 3, 22]);
+}
+
+// Cache the results of a pure unary function: each distinct argument is
+// computed once, later calls are a HashMap lookup (fib_memo, generalized)
+struct Memoize<A, B, F> {
+    f: F,
+    cache: HashMap<A, B>,
+}
+
+impl<A, B, F> Memoize<A, B, F>
+where
+    A: Eq + Hash + Clone,
+    B: Clone,
+    F: Fn(A) -> B,
+{
+    fn new(f: F) -> Self {
+        Memoize { f, cache: HashMap::new() }
+    }
+
+    fn call(&mut self, arg: A) -> B {
+        if let Some(hit) = self.cache.get(&arg) {
+            return hit.clone();
+        }
+        let value = (self.f)(arg.clone());
+        self.cache.insert(arg, value.clone());
+        value
+    }
+}
+
+fn test_memoize() {
+    let calls = std::cell::Cell::new(0);
+    let mut square = Memoize::new(|x: u64| {
+        calls.set(calls.get() + 1);
+        x * x
+    });
+    assert_eq!(square.call(4), 16);
+    assert_eq!(square.call(4), 16);
+    assert_eq!(square.call(5), 25);
+    assert_eq!(square.call(4), 16);
+    assert_eq!(calls.get(), 2);
+}
+
+fn test_split_on() {
+    let parts = [1, 2, 0, 3, 4, 0, 5].into_iter().split_on(|&x| x == 0);
+    assert_eq!(parts, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    let parts = [0, 0, 1].into_iter().split_on(|&x| x == 0);
+    assert_eq!(parts, vec![vec![], vec![], vec![1]]);
+}
+
+// Left rotation by n mod len into a new Vec, leaving items untouched:
+// the suffix starting at the split point, then the prefix
+fn rotate_iter<T: Clone>(items: &[T], n: usize) -> Vec<T> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+    let mid = n % items.len();
+    items[mid..].iter().chain(&items[..mid]).cloned().collect()
+}
+
+fn test_rotate_iter() {
+    assert_eq!(rotate_iter(&[1, 2, 3, 4, 5], 7), vec![3, 4, 5, 1, 2]);
+    assert_eq!(rotate_iter(&[1, 2, 3], 3), vec![1, 2, 3]);
+    assert!(rotate_iter::<i32>(&[], 4).is_empty());
 }
@@ -12,6 +12,11 @@ impl Counter {
     fn new(max : u32) -> Counter {
         Counter { count: 0, max }
     }
+
+    // Yield values only while the predicate holds (wraps take_while)
+    fn up_to_first<P: Fn(u32) -> bool>(self, pred: P) -> impl Iterator<Item = u32> {
+        self.take_while(move |&x| pred(x))
+    }
 }
 
 impl Iterator for Counter {
@@ -25,9 +30,97 @@ impl Iterator for Counter {
         }
     }
 }
+
+fn test_up_to_first() {
+    let values: Vec<u32> = Counter::new(35).up_to_first(|x| x % 7 != 0).collect();
+    assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+}
+
+// Fibonacci numbers bounded by a maximum.
+// An unbounded generator has no last element, so there is nowhere for
+// next_back to start; only once up_to() has fixed the bound can the
+// iterator be consumed from both ends.
+struct Fibonacci {
+    front: (u64, u64), // (F(i), F(i+1)): next value from the front
+    back: (u64, u64),  // (F(j-1), F(j)): next value from the back
+    remaining: usize,
+}
+
+impl Fibonacci {
+    fn up_to(max: u64) -> Fibonacci {
+        // Walk forward once to find the last value <= max (F(-1) = 1)
+        let (mut prev, mut curr) = (1u64, 0u64);
+        let mut back = (prev, curr);
+        let mut remaining = 0;
+        while curr <= max {
+            back = (prev, curr);
+            remaining += 1;
+            match prev.checked_add(curr) {
+                Some(next) => {
+                    prev = curr;
+                    curr = next;
+                }
+                None => break,
+            }
+        }
+        Fibonacci { front: (0, 1), back, remaining }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (a, b) = self.front;
+        self.front = (b, a.wrapping_add(b)); // past the bound, never yielded
+        Some(a)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl DoubleEndedIterator for Fibonacci {
+    // Step backwards with F(j-2) = F(j) - F(j-1)
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let (p, c) = self.back;
+        self.back = (c.wrapping_sub(p), p); // wraps only below F(0), never yielded
+        Some(c)
+    }
+}
+
+fn test_fibonacci_double_ended() {
+    let forward: Vec<u64> = Fibonacci::up_to(100).collect();
+    assert_eq!(forward, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+
+    let mut backward: Vec<u64> = Fibonacci::up_to(100).rev().collect();
+    backward.reverse();
+    assert_eq!(backward, forward);
+
+    let mut fib = Fibonacci::up_to(10);
+    assert_eq!(fib.next(), Some(0));
+    assert_eq!(fib.next_back(), Some(8));
+    assert_eq!(fib.next_back(), Some(5));
+    assert_eq!(fib.collect::<Vec<_>>(), vec![1, 1, 2, 3]);
+
+    assert_eq!(Fibonacci::up_to(u64::MAX).count(), 94);
+    assert_eq!(Fibonacci::up_to(u64::MAX).next_back(), Some(12_200_160_415_121_876_738));
+}
+
 fn main() {
     let mut counter = Counter::new(35);
     while let Some(x) = counter.next() {
         println!("{}", x);
     }
+
+    test_up_to_first();
+    test_fibonacci_double_ended();
 }
\ No newline at end of file
@@ -1,3 +1,6 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::hash::Hash;
 use std::ops::Index;
 
 // ============================================
@@ -20,6 +23,17 @@ impl<T> SimpleVector<T> {
     fn iter(&self) -> std::slice::Iter<T> {
         self.data.iter()
     }
+
+    // Consume the container, yielding elements back-to-front.
+    // Vec's IntoIter is double-ended, so rev() comes for free here.
+    fn into_iter_rev(self) -> impl Iterator<Item = T> {
+        self.data.into_iter().rev()
+    }
+
+    // By-value map: each owned element is moved into f
+    fn map<U, F: Fn(T) -> U>(self, f: F) -> SimpleVector<U> {
+        SimpleVector { data: self.data.into_iter().map(f).collect() }
+    }
 }
 
 // ============================================
@@ -102,9 +116,12 @@ struct FullVector<T> {
     data: Vec<T>,
 }
 
+// Two cursors: index moves forward, back moves backward; the
+// elements still to be yielded are data[index..back]
 struct FullVectorIter<'a, T> {
     data: &'a Vec<T>,
     index: usize,
+    back: usize,
 }
 
 impl<T> FullVector<T> {
@@ -120,19 +137,128 @@ impl<T> FullVector<T> {
         FullVectorIter {
             data: &self.data,
             index: 0,
+            back: self.data.len(),
         }
     }
 
+    // Explicit reverse entry point; relies on the DoubleEndedIterator impl
+    fn riter(&self) -> std::iter::Rev<FullVectorIter<'_, T>> {
+        self.iter().rev()
+    }
+
     fn iter_mut(&mut self) -> std::slice::IterMut<T> {
         self.data.iter_mut()
     }
+
+    // Rotate in place like slice::rotate_left / rotate_right.
+    // mid == 0 and mid == len are no-ops; panics if mid > len.
+    fn rotate_left(&mut self, mid: usize) {
+        self.data.rotate_left(mid);
+    }
+
+    fn rotate_right(&mut self, mid: usize) {
+        self.data.rotate_right(mid);
+    }
+
+    // Drop consecutive elements whose keys are equal (like Vec::dedup_by_key)
+    fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.data.dedup_by_key(|x| key(x));
+    }
+
+    // Same contract as slice::binary_search; the container must be sorted.
+    // Ok(i) = found at i, Err(i) = where target would be inserted.
+    fn binary_search(&self, target: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.data.binary_search(target)
+    }
+
+    // Sorting delegates to the inner Vec (stable, like slice::sort)
+    fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.data.sort();
+    }
+
+    fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, f: F) {
+        self.data.sort_by(f);
+    }
+
+    // Consume the container and hand back its elements sorted ascending
+    fn into_sorted_vec(self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut data = self.data;
+        data.sort();
+        data
+    }
+
+    // Panics if either index is out of bounds, like slice::swap
+    fn swap(&mut self, i: usize, j: usize) {
+        self.data.swap(i, j);
+    }
+
+    // Keep the first len elements; no-op if len >= the current length
+    fn truncate(&mut self, len: usize) {
+        self.data.truncate(len);
+    }
+
+    // Like retain, but the removed elements are handed back instead of
+    // dropped. Both halves keep their original relative order.
+    fn extract_if<P: FnMut(&T) -> bool>(&mut self, mut pred: P) -> Vec<T> {
+        let (removed, kept) = std::mem::take(&mut self.data)
+            .into_iter()
+            .partition(|x| pred(x));
+        self.data = kept;
+        removed
+    }
+
+    // Apply f to every sliding window of the backing slice.
+    // Panics if size == 0, like slice::windows.
+    fn map_windows<U, F: Fn(&[T]) -> U>(&self, size: usize, f: F) -> Vec<U> {
+        self.data.windows(size).map(f).collect()
+    }
+
+    // Distinct elements as a set, for fast membership checks
+    fn to_hashset(&self) -> HashSet<T>
+    where
+        T: Eq + Hash + Clone,
+    {
+        self.data.iter().cloned().collect()
+    }
+
+    // Fallible map: collecting into Result stops at the first Err
+    fn try_map<U, E, F: Fn(&T) -> Result<U, E>>(&self, f: F) -> Result<FullVector<U>, E> {
+        let data = self.data.iter().map(f).collect::<Result<Vec<U>, E>>()?;
+        Ok(FullVector { data })
+    }
+
+    // Consume both containers: self's elements first, then other's
+    fn concat(mut self, other: FullVector<T>) -> FullVector<T> {
+        self.data.extend(other.data);
+        self
+    }
+
+    // Same contract as slice::partition_point: assuming every element
+    // matching pred comes first, return the index of the first one that does not
+    fn partition_point<P: Fn(&T) -> bool>(&self, pred: P) -> usize {
+        self.data.partition_point(pred)
+    }
+
+    // How many elements satisfy pred
+    fn count_where<P: Fn(&T) -> bool>(&self, pred: P) -> usize {
+        self.data.iter().filter(|x| pred(x)).count()
+    }
 }
 
 impl<'a, T> Iterator for FullVectorIter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.data.len() {
+        if self.index < self.back {
             let item = &self.data[self.index];
             self.index += 1;
             Some(item)
@@ -145,19 +271,31 @@ impl<'a, T> Iterator for FullVectorIter<'a, T> {
 // Optional: implement ExactSizeIterator
 impl<'a, T> ExactSizeIterator for FullVectorIter<'a, T> {
     fn len(&self) -> usize {
-        self.data.len() - self.index
+        self.back - self.index
     }
 }
 
 // Optional: implement DoubleEndedIterator
 impl<'a, T> DoubleEndedIterator for FullVectorIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.index < self.data.len() {
-            Some(&self.data[self.data.len() - 1 - self.index])
+        if self.index < self.back {
+            self.back -= 1;
+            Some(&self.data[self.back])
         } else {
             None
         }
     }
+
+    // Jump the back cursor in one step instead of calling next_back n times
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n < self.back - self.index {
+            self.back -= n + 1;
+            Some(&self.data[self.back])
+        } else {
+            self.back = self.index;
+            None
+        }
+    }
 }
 
 // Optional: implement IntoIterator for for loops
@@ -170,6 +308,16 @@ impl<'a, T> IntoIterator for &'a FullVector<T> {
     }
 }
 
+// Mutable borrowing iteration: for x in &mut fv { *x += 1 }
+impl<'a, T> IntoIterator for &'a mut FullVector<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 fn main() {
     println!("=== APPROACH 1: Simple (reuse Vec iterator) ===");
     let mut sv = SimpleVector::new();
@@ -229,4 +377,187 @@ fn main() {
     println!("Iterator length: {}", iter.len());
     iter.next();
     println!("After next(), length: {}", iter.len());
-}
\ No newline at end of file
+
+    // ----- Tests -----
+    test_rotate();
+    test_dedup_by_key();
+    test_into_iter_rev();
+    test_binary_search();
+    test_sort();
+    test_into_sorted_vec();
+    test_swap_truncate();
+    test_extract_if();
+    test_simple_vector_map();
+    test_map_windows();
+    test_to_hashset();
+    test_nth_back();
+    test_riter();
+    test_try_map();
+    test_into_iter_mut();
+    test_concat();
+    test_partition_point();
+    test_count_where();
+}
+
+// True if f panics; the default hook is silenced so the demo output stays clean
+fn panics<F: FnOnce() + std::panic::UnwindSafe>(f: F) -> bool {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = std::panic::catch_unwind(f);
+    std::panic::set_hook(hook);
+    result.is_err()
+}
+
+fn test_rotate() {
+    let mut fv = FullVector { data: vec![1, 2, 3, 4, 5] };
+    fv.rotate_left(2);
+    assert_eq!(fv.data, vec![3, 4, 5, 1, 2]);
+    fv.rotate_right(2);
+    assert_eq!(fv.data, vec![1, 2, 3, 4, 5]);
+
+    fv.rotate_left(0);
+    fv.rotate_right(5);
+    assert_eq!(fv.data, vec![1, 2, 3, 4, 5]);
+
+    assert!(panics(move || fv.rotate_left(6)));
+}
+
+fn test_dedup_by_key() {
+    let mut fv = FullVector { data: vec![1, -1, 2, -2, -2] };
+    fv.dedup_by_key(|x: &i32| x.abs());
+    assert_eq!(fv.data, vec![1, 2]);
+}
+
+fn test_into_iter_rev() {
+    let mut sv = SimpleVector::new();
+    sv.push(1);
+    sv.push(2);
+    sv.push(3);
+    assert_eq!(sv.into_iter_rev().collect::<Vec<i32>>(), vec![3, 2, 1]);
+}
+
+fn test_binary_search() {
+    let fv = FullVector { data: vec![1, 3, 5, 7, 9] };
+    assert_eq!(fv.binary_search(&7), Ok(3));
+    assert_eq!(fv.binary_search(&4), Err(2));
+    assert_eq!(fv.binary_search(&10), Err(5));
+}
+
+fn test_sort() {
+    let mut fv = FullVector { data: vec![5, 1, 4, 2, 3] };
+    fv.sort();
+    assert_eq!(fv.data, vec![1, 2, 3, 4, 5]);
+    fv.sort_by(|a, b| b.cmp(a));
+    assert_eq!(fv.data, vec![5, 4, 3, 2, 1]);
+}
+
+fn test_into_sorted_vec() {
+    let mut fv = FullVector::new();
+    for x in [4, 2, 9, 1, 7] {
+        fv.push(x);
+    }
+    assert_eq!(fv.into_sorted_vec(), vec![1, 2, 4, 7, 9]);
+}
+
+fn test_swap_truncate() {
+    let mut fv = FullVector { data: vec![1, 2, 3, 4] };
+    fv.swap(0, 3);
+    assert_eq!(fv.data, vec![4, 2, 3, 1]);
+    assert!(panics(|| FullVector { data: vec![1] }.swap(0, 1)));
+
+    fv.truncate(10);
+    assert_eq!(fv.data, vec![4, 2, 3, 1]);
+    fv.truncate(2);
+    assert_eq!(fv.data, vec![4, 2]);
+}
+
+fn test_extract_if() {
+    let mut fv = FullVector { data: vec![1, 2, 3, 4] };
+    assert_eq!(fv.extract_if(|x| x % 2 == 0), vec![2, 4]);
+    assert_eq!(fv.data, vec![1, 3]);
+}
+
+fn test_simple_vector_map() {
+    let mut sv = SimpleVector::new();
+    sv.push(1);
+    sv.push(2);
+    sv.push(3);
+    let strings = sv.map(|x| x.to_string());
+    assert_eq!(strings.data, vec!["1", "2", "3"]);
+}
+
+fn test_map_windows() {
+    let fv = FullVector { data: vec![1, 5, 2, 8, 3] };
+    let maxima = fv.map_windows(2, |w| *w.iter().max().unwrap());
+    assert_eq!(maxima, vec![5, 5, 8, 8]);
+}
+
+fn test_to_hashset() {
+    let fv = FullVector { data: vec![1, 2, 2, 3] };
+    let set = fv.to_hashset();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&2));
+}
+
+fn test_nth_back() {
+    let fv = FullVector { data: vec![1, 2, 3, 4] };
+    let mut iter = fv.iter();
+    assert_eq!(iter.nth_back(1), Some(&3));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = fv.iter();
+    assert_eq!(iter.nth_back(4), None);
+    assert_eq!(iter.len(), 0);
+
+    let rev: Vec<i32> = fv.iter().rev().copied().collect();
+    assert_eq!(rev, vec![4, 3, 2, 1]);
+}
+
+fn test_riter() {
+    let fv = FullVector { data: vec![5, 10, 15] };
+    assert_eq!(fv.riter().copied().collect::<Vec<_>>(), vec![15, 10, 5]);
+    assert_eq!(fv.riter().len(), 3);
+}
+
+fn test_try_map() {
+    let fv = FullVector { data: vec!["1", "2", "3"] };
+    let parsed = fv.try_map(|s| s.parse::<i32>()).unwrap();
+    assert_eq!(parsed.data, vec![1, 2, 3]);
+
+    let calls = std::cell::Cell::new(0);
+    let fv = FullVector { data: vec!["1", "x", "3"] };
+    let result = fv.try_map(|s| {
+        calls.set(calls.get() + 1);
+        s.parse::<i32>()
+    });
+    assert!(result.is_err());
+    assert_eq!(calls.get(), 2);
+}
+
+fn test_into_iter_mut() {
+    let mut fv = FullVector { data: vec![1, 2, 3] };
+    for x in &mut fv {
+        *x += 1;
+    }
+    assert_eq!(fv.data, vec![2, 3, 4]);
+}
+
+fn test_concat() {
+    let a = FullVector { data: vec![1, 2] };
+    let b = FullVector { data: vec![3, 4] };
+    assert_eq!(a.concat(b).data, vec![1, 2, 3, 4]);
+}
+
+fn test_partition_point() {
+    let fv = FullVector { data: vec![1, 2, 4, 5, 7, 9] };
+    assert_eq!(fv.partition_point(|&x| x < 5), 3);
+    assert_eq!(fv.partition_point(|&x| x < 100), 6);
+}
+
+fn test_count_where() {
+    let fv = FullVector { data: vec![3, 8, 1, 12, 5, 9] };
+    assert_eq!(fv.count_where(|&x| x > 5), 3);
+    assert_eq!(fv.count_where(|&x| x > 100), 0);
+}
@@ -116,6 +116,22 @@ fn train_animal(animal: &mut dyn Trainable) {
     println!("Skill level now: {}", animal.skill_level());
 }
 
+// Sort boxed trainables by skill, best first (ties keep their order)
+fn leaderboard(pets: &mut Vec<Box<dyn Trainable>>) {
+    pets.sort_by_key(|pet| std::cmp::Reverse(pet.skill_level()));
+}
+
+fn test_leaderboard() {
+    let mut pets: Vec<Box<dyn Trainable>> = vec![
+        Box::new(Pet { name: "Fido".to_string(), skill: 2 }),
+        Box::new(Pet { name: "Rex".to_string(), skill: 7 }),
+        Box::new(Pet { name: "Spot".to_string(), skill: 4 }),
+    ];
+    leaderboard(&mut pets);
+    let skills: Vec<u32> = pets.iter().map(|pet| pet.skill_level()).collect();
+    assert_eq!(skills, vec![7, 4, 2]);
+}
+
 // ============================================
 // 6. OBJECT SAFETY RULES
 // ============================================
@@ -152,9 +168,25 @@ impl Drawable for Rectangle {
 // ============================================
 // 7. PRACTICAL EXAMPLE: PLUGIN SYSTEM
 // ============================================
+#[derive(Debug, Clone, PartialEq)]
+struct PluginMetadata {
+    name: String,
+    version: String,
+    description: String,
+}
+
 trait Plugin {
     fn name(&self) -> &str;
-    fn execute(&self);
+    fn execute(&self) -> Result<(), String>;
+    
+    // Default method: plugins only override it when they have more to say
+    fn metadata(&self) -> PluginMetadata {
+        PluginMetadata {
+            name: self.name().to_string(),
+            version: "unknown".to_string(),
+            description: String::new(),
+        }
+    }
 }
 
 struct LoggerPlugin;
@@ -165,8 +197,9 @@ impl Plugin for LoggerPlugin {
         "Logger"
     }
     
-    fn execute(&self) {
+    fn execute(&self) -> Result<(), String> {
         println!("[Logger] Logging data...");
+        Ok(())
     }
 }
 
@@ -175,8 +208,17 @@ impl Plugin for MetricsPlugin {
         "Metrics"
     }
     
-    fn execute(&self) {
+    fn execute(&self) -> Result<(), String> {
         println!("[Metrics] Collecting metrics...");
+        Ok(())
+    }
+    
+    fn metadata(&self) -> PluginMetadata {
+        PluginMetadata {
+            name: self.name().to_string(),
+            version: "1.2.0".to_string(),
+            description: "Collects runtime metrics".to_string(),
+        }
     }
 }
 
@@ -195,14 +237,84 @@ impl PluginManager {
         self.plugins.push(plugin);
     }
     
-    fn run_all(&self) {
+    // Runs every plugin and collects all failures as (plugin name, error)
+    fn run_all(&self) -> Vec<(String, String)> {
+        let mut errors = Vec::new();
         for plugin in &self.plugins {
             println!("Running plugin: {}", plugin.name());
-            plugin.execute();
+            if let Err(e) = plugin.execute() {
+                errors.push((plugin.name().to_string(), e));
+            }
+        }
+        errors
+    }
+    
+    // Runs plugins in order and stops at the first failure
+    fn run_until_error(&mut self) -> Result<(), (String, String)> {
+        for plugin in &self.plugins {
+            plugin
+                .execute()
+                .map_err(|e| (plugin.name().to_string(), e))?;
         }
+        Ok(())
+    }
+    
+    fn list_metadata(&self) -> Vec<PluginMetadata> {
+        self.plugins.iter().map(|plugin| plugin.metadata()).collect()
     }
 }
 
+fn test_list_metadata() {
+    let mut manager = PluginManager::new();
+    manager.register(Box::new(LoggerPlugin));
+    manager.register(Box::new(MetricsPlugin));
+    
+    let metadata = manager.list_metadata();
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata[0], PluginMetadata {
+        name: "Logger".to_string(),
+        version: "unknown".to_string(),
+        description: String::new(),
+    });
+    assert_eq!(metadata[1].name, "Metrics");
+    assert_eq!(metadata[1].version, "1.2.0");
+    assert_eq!(metadata[1].description, "Collects runtime metrics");
+}
+
+fn test_run_until_error() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+    
+    // Records whether it ran, and fails on demand
+    struct Probe {
+        name: &'static str,
+        fail: bool,
+        ran: Rc<Cell<bool>>,
+    }
+    
+    impl Plugin for Probe {
+        fn name(&self) -> &str {
+            self.name
+        }
+        
+        fn execute(&self) -> Result<(), String> {
+            self.ran.set(true);
+            if self.fail { Err("boom".to_string()) } else { Ok(()) }
+        }
+    }
+    
+    let ran: Vec<Rc<Cell<bool>>> = (0..3).map(|_| Rc::new(Cell::new(false))).collect();
+    let mut manager = PluginManager::new();
+    for (i, name) in ["first", "second", "third"].into_iter().enumerate() {
+        manager.register(Box::new(Probe { name, fail: i == 1, ran: Rc::clone(&ran[i]) }));
+    }
+    
+    assert_eq!(manager.run_until_error(), Err(("second".to_string(), "boom".to_string())));
+    assert!(ran[0].get());
+    assert!(ran[1].get());
+    assert!(!ran[2].get());
+}
+
 // ============================================
 // 8. PERFORMANCE CONSIDERATIONS
 // ============================================
@@ -275,6 +387,11 @@ fn main() {
     
     // VTable demonstration
     demonstrate_vtable_concept();
+    
+    // Tests
+    test_leaderboard();
+    test_list_metadata();
+    test_run_until_error();
 }
 
 // ============================================